@@ -0,0 +1,234 @@
+//! Binary-search-tree operations for ordered values.
+
+use crate::BinTree;
+
+impl<T: Ord> BinTree<T> {
+    /// Inserts `value` into the tree, descending left when it's smaller than a
+    /// node's value and right otherwise, creating a new leaf at the first empty
+    /// slot found. Does not rebalance the tree.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use bintree::BinTree;
+    ///
+    /// let mut tree = BinTree::new(5);
+    /// tree.insert(3);
+    /// tree.insert(8);
+    ///
+    /// assert_eq!(tree.get_left().unwrap().get_inner(), &3);
+    /// assert_eq!(tree.get_right().unwrap().get_inner(), &8);
+    /// ```
+    pub fn insert(&mut self, value: T) {
+        let mut node = self;
+
+        loop {
+            let next = if value < node.inner {
+                &mut node.left
+            } else {
+                &mut node.right
+            };
+
+            match next {
+                Some(child) => node = child,
+                None => {
+                    *next = Some(Self::new(value).boxed());
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if `value` is present somewhere in the tree.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use bintree::BinTree;
+    ///
+    /// let mut tree = BinTree::new(5);
+    /// tree.insert(3);
+    ///
+    /// assert!(tree.contains(&3));
+    /// assert!(!tree.contains(&4));
+    /// ```
+    pub fn contains(&self, value: &T) -> bool {
+        let mut node = self;
+
+        loop {
+            if *value == node.inner {
+                return true;
+            }
+
+            let next = if *value < node.inner {
+                node.get_left()
+            } else {
+                node.get_right()
+            };
+
+            match next {
+                Some(child) => node = child,
+                None => return false,
+            }
+        }
+    }
+
+    /// Removes `value` from the tree if present, returning it.
+    ///
+    /// Deletion follows the standard three cases: a leaf is simply detached, a
+    /// node with a single child is replaced by that child, and a node with two
+    /// children is replaced by the right-most descendant of its left subtree.
+    ///
+    /// ## Note
+    /// A `BinTree` always holds a value, so removing the root itself when it
+    /// has no children is impossible (there is nothing to put in its place);
+    /// in that case this returns `None` and leaves the tree untouched.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use bintree::BinTree;
+    ///
+    /// let mut tree = BinTree::new(5);
+    /// tree.insert(3);
+    /// tree.insert(8);
+    /// tree.insert(1);
+    /// tree.insert(4);
+    /// tree.insert(7);
+    /// tree.insert(9);
+    ///
+    /// /*
+    ///   Tree:
+    ///        (5)
+    ///       /    \
+    ///     (3)    (8)
+    ///    /   \   /   \
+    ///  (1)  (4)(7)  (9)
+    /// */
+    ///
+    /// // Leaf case.
+    /// assert_eq!(tree.remove(&1), Some(1));
+    /// assert!(!tree.contains(&1));
+    ///
+    /// // Another leaf: removing `7` leaves `8` with only its right child,
+    /// // `9`.
+    /// assert_eq!(tree.remove(&7), Some(7));
+    /// assert!(!tree.contains(&7));
+    /// assert_eq!(tree.get_right().unwrap().get_inner(), &8);
+    /// assert_eq!(tree.get_right().unwrap().get_right().unwrap().get_inner(), &9);
+    ///
+    /// // Two-children case: `5` is replaced by `4`, the right-most
+    /// // descendant of its left subtree.
+    /// assert_eq!(tree.remove(&5), Some(5));
+    /// assert!(!tree.contains(&5));
+    /// assert_eq!(tree.get_inner(), &4);
+    /// assert_eq!(tree.get_left().unwrap().get_inner(), &3);
+    /// assert_eq!(tree.get_right().unwrap().get_inner(), &8);
+    /// ```
+    pub fn remove(&mut self, value: &T) -> Option<T> {
+        if *value == self.inner {
+            return self.remove_root();
+        }
+
+        let slot = Self::find_slot(self, value)?;
+        let node = slot.take()?;
+        let (removed, replacement) = Self::detach(*node);
+        *slot = replacement;
+        Some(removed)
+    }
+
+    /// Replaces the root's value and children in place with whatever should
+    /// take over after removing it, since the root can't simply be dropped
+    /// from a parent slot the way a descendant can.
+    fn remove_root(&mut self) -> Option<T> {
+        let left = self.left.take();
+        let right = self.right.take();
+
+        match (left, right) {
+            (None, None) => None,
+            (Some(child), None) | (None, Some(child)) => {
+                let child = *child;
+                let removed = std::mem::replace(&mut self.inner, child.inner);
+                self.left = child.left;
+                self.right = child.right;
+                Some(removed)
+            }
+            (Some(left), Some(right)) => {
+                let (successor, new_left) = Self::detach_rightmost(left);
+                let removed = std::mem::replace(&mut self.inner, successor.inner);
+                self.left = new_left;
+                self.right = Some(right);
+                Some(removed)
+            }
+        }
+    }
+
+    /// Finds the `Option<Box<Self>>` slot holding the node for `value` among
+    /// `root`'s descendants. Returns `None` if `value` isn't found below `root`.
+    fn find_slot<'a>(root: &'a mut Self, value: &T) -> Option<&'a mut Option<Box<Self>>> {
+        let mut slot = if *value < root.inner {
+            &mut root.left
+        } else {
+            &mut root.right
+        };
+
+        loop {
+            slot.as_ref()?;
+            let found = *value == slot.as_ref().unwrap().inner;
+
+            if found {
+                return Some(slot);
+            }
+
+            let node = slot.as_mut().unwrap();
+            slot = if *value < node.inner {
+                &mut node.left
+            } else {
+                &mut node.right
+            };
+        }
+    }
+
+    /// Splits a detached node into its inner value and the subtree that
+    /// should replace it in its parent's slot, implementing the two-child
+    /// deletion case by splicing in the right-most descendant of the left
+    /// subtree.
+    fn detach(node: Self) -> (T, Option<Box<Self>>) {
+        let Self { inner, left, right } = node;
+
+        let replacement = match (left, right) {
+            (None, None) => None,
+            (Some(child), None) | (None, Some(child)) => Some(child),
+            (Some(left), Some(right)) => {
+                let (mut successor, new_left) = Self::detach_rightmost(left);
+                successor.left = new_left;
+                successor.right = Some(right);
+                Some(successor)
+            }
+        };
+
+        (inner, replacement)
+    }
+
+    /// Detaches the right-most descendant of `node`, promoting its own left
+    /// subtree (if any) into the gap it leaves behind.
+    ///
+    /// Returns the detached node (with both of its children cleared) and the
+    /// subtree that should take `node`'s place.
+    ///
+    /// Walks the right spine iteratively rather than recursing one stack
+    /// frame per node, since that spine can be as deep as the tree itself.
+    fn detach_rightmost(mut node: Box<Self>) -> (Box<Self>, Option<Box<Self>>) {
+        if node.right.is_none() {
+            let replacement = node.left.take();
+            return (node, replacement);
+        }
+
+        let mut parent = &mut node;
+        while parent.right.as_ref().unwrap().right.is_some() {
+            parent = parent.right.as_mut().unwrap();
+        }
+
+        let mut rightmost = parent.right.take().unwrap();
+        parent.right = rightmost.left.take();
+
+        (rightmost, Some(node))
+    }
+}
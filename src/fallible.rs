@@ -0,0 +1,161 @@
+//! Fallible, allocation-aware counterparts to the infallible constructors.
+//!
+//! Every `set_left`/`set_right`/`boxed`/[`Clone`] on [`BinTree`] boxes its
+//! argument with the ordinary, abort-on-OOM `Box::new`. The methods here
+//! route allocation through [`Vec::try_reserve_exact`] instead, so a failed
+//! allocation anywhere in a deep tree surfaces as an `Err` rather than
+//! aborting the process.
+
+use crate::BinTree;
+use std::collections::TryReserveError;
+
+/// Boxes `value` without risking an abort on allocation failure.
+///
+/// [`Box::new`] has no fallible counterpart on stable Rust, so this goes
+/// through a one-element [`Vec`] instead: [`Vec::try_reserve_exact`] surfaces the
+/// allocation failure as a [`TryReserveError`], and the vec's buffer is then
+/// reclaimed as the box's storage.
+fn try_new_boxed<T>(value: T) -> Result<Box<T>, TryReserveError> {
+    let mut storage = Vec::with_capacity(0);
+    storage.try_reserve_exact(1)?;
+    storage.push(value);
+
+    let mut boxed_slice = storage.into_boxed_slice();
+    let ptr = boxed_slice.as_mut_ptr();
+    std::mem::forget(boxed_slice);
+
+    // SAFETY: `ptr` points at the sole element of a one-element allocation
+    // sized and aligned for `T`, and `mem::forget` above stops the boxed
+    // slice from freeing it out from under us.
+    Ok(unsafe { Box::from_raw(ptr) })
+}
+
+impl<T> BinTree<T> {
+    /// Fallible counterpart to [`boxed`](Self::boxed).
+    ///
+    /// ## Example
+    /// ```rust
+    /// use bintree::BinTree;
+    ///
+    /// let tree = BinTree::new(25);
+    /// let boxed = tree.try_boxed().unwrap();
+    ///
+    /// assert_eq!(boxed.get_inner(), &25);
+    /// ```
+    pub fn try_boxed(self) -> Result<Box<Self>, TryReserveError> {
+        try_new_boxed(self)
+    }
+
+    /// Fallible counterpart to [`set_left`](Self::set_left).
+    ///
+    /// ## Example
+    /// ```rust
+    /// use bintree::BinTree;
+    ///
+    /// let mut tree = BinTree::new(8);
+    /// tree.try_set_left(Some(9)).unwrap();
+    ///
+    /// assert_eq!(tree.get_left().unwrap().get_inner(), &9);
+    ///
+    /// tree.try_set_left(None).unwrap();
+    /// assert!(tree.get_left().is_none());
+    /// ```
+    pub fn try_set_left(&mut self, value: Option<T>) -> Result<(), TryReserveError> {
+        match value {
+            Some(value) => self.left = Some(try_new_boxed(Self::new(value))?),
+            None => self.clear_left(),
+        }
+        Ok(())
+    }
+
+    /// Fallible counterpart to [`set_right`](Self::set_right).
+    ///
+    /// ## Example
+    /// ```rust
+    /// use bintree::BinTree;
+    ///
+    /// let mut tree = BinTree::new(8);
+    /// tree.try_set_right(Some(9)).unwrap();
+    ///
+    /// assert_eq!(tree.get_right().unwrap().get_inner(), &9);
+    ///
+    /// tree.try_set_right(None).unwrap();
+    /// assert!(tree.get_right().is_none());
+    /// ```
+    pub fn try_set_right(&mut self, value: Option<T>) -> Result<(), TryReserveError> {
+        match value {
+            Some(value) => self.right = Some(try_new_boxed(Self::new(value))?),
+            None => self.clear_right(),
+        }
+        Ok(())
+    }
+
+    /// Fallible counterpart to [`new_with_nodes`](Self::new_with_nodes).
+    ///
+    /// ## Example
+    /// ```rust
+    /// use bintree::BinTree;
+    ///
+    /// let tree = BinTree::try_new_with_nodes(1, 2, 3).unwrap();
+    ///
+    /// assert_eq!(tree.get_inner(), &1);
+    /// assert_eq!(tree.get_left().unwrap().get_inner(), &2);
+    /// assert_eq!(tree.get_right().unwrap().get_inner(), &3);
+    /// ```
+    pub fn try_new_with_nodes(inner: T, left: T, right: T) -> Result<Self, TryReserveError> {
+        let left = try_new_boxed(Self::new(left))?;
+        let right = try_new_boxed(Self::new(right))?;
+
+        Ok(Self {
+            inner,
+            left: Some(left),
+            right: Some(right),
+        })
+    }
+}
+
+impl<T: Clone> BinTree<T> {
+    /// Fallible counterpart to [`Clone::clone`], recursively cloning both
+    /// subtrees and propagating any allocation failure instead of unwinding
+    /// or aborting partway through a deep tree.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use bintree::BinTree;
+    ///
+    /// let mut original = BinTree::new_with_nodes(1, 2, 3);
+    /// let mut cloned = original.try_clone().unwrap();
+    ///
+    /// // The clone is a deep copy: mutating one doesn't affect the other.
+    /// cloned.set_inner(99);
+    /// original.get_left_mut().unwrap().set_inner(50);
+    ///
+    /// assert_eq!(original.get_inner(), &1);
+    /// assert_eq!(original.get_left().unwrap().get_inner(), &50);
+    /// assert_eq!(cloned.get_inner(), &99);
+    /// assert_eq!(cloned.get_left().unwrap().get_inner(), &2);
+    /// ```
+    pub fn try_clone(&self) -> Result<Self, TryReserveError> {
+        let left = self
+            .left
+            .as_deref()
+            .map(BinTree::try_clone)
+            .transpose()?
+            .map(try_new_boxed)
+            .transpose()?;
+
+        let right = self
+            .right
+            .as_deref()
+            .map(BinTree::try_clone)
+            .transpose()?
+            .map(try_new_boxed)
+            .transpose()?;
+
+        Ok(Self {
+            inner: self.inner.clone(),
+            left,
+            right,
+        })
+    }
+}
@@ -0,0 +1,552 @@
+//! Traversal iterators for [`BinTree`](crate::BinTree).
+//!
+//! The pre-order, in-order and breadth-first iterators are lazy. The
+//! post-order iterators are not: they collect the whole traversal order when
+//! they are created and then hand out one value per call to `next`.
+//!
+//! Every iterator here walks the tree using an explicit stack (or queue, for
+//! breadth-first traversal) instead of recursion, so traversing a very deep
+//! tree never risks blowing the call stack.
+
+use crate::BinTree;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+impl<T> BinTree<T> {
+    /// Returns an iterator that walks the tree pre-order (node, left, right),
+    /// yielding `&T`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use bintree::BinTree;
+    ///
+    /// let tree = BinTree::new_with_nodes(1, 2, 3);
+    /// let values: Vec<_> = tree.iter_preorder().collect();
+    ///
+    /// assert_eq!(values, vec![&1, &2, &3]);
+    /// ```
+    pub fn iter_preorder(&self) -> PreOrderIter<'_, T> {
+        PreOrderIter { stack: vec![self] }
+    }
+
+    /// Returns a mutable pre-order iterator, yielding `&mut T`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use bintree::BinTree;
+    ///
+    /// let mut tree = BinTree::new_with_nodes(1, 2, 3);
+    /// for value in tree.iter_preorder_mut() {
+    ///     *value *= 10;
+    /// }
+    ///
+    /// let values: Vec<_> = tree.iter_preorder().collect();
+    /// assert_eq!(values, vec![&10, &20, &30]);
+    /// ```
+    pub fn iter_preorder_mut(&mut self) -> PreOrderIterMut<'_, T> {
+        PreOrderIterMut {
+            stack: vec![NonNull::from(self)],
+            _marker: PhantomData,
+        }
+    }
+
+    /// Consumes the tree and returns an owned pre-order iterator, yielding `T`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use bintree::BinTree;
+    ///
+    /// let tree = BinTree::new_with_nodes(1, 2, 3);
+    /// let values: Vec<_> = tree.into_preorder().collect();
+    ///
+    /// assert_eq!(values, vec![1, 2, 3]);
+    /// ```
+    pub fn into_preorder(self) -> IntoPreOrderIter<T> {
+        IntoPreOrderIter {
+            stack: vec![self.boxed()],
+        }
+    }
+
+    /// Returns an iterator that walks the tree in-order (left, node, right),
+    /// yielding `&T`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use bintree::BinTree;
+    ///
+    /// let tree = BinTree::new_with_nodes(2, 1, 3);
+    /// let values: Vec<_> = tree.iter_inorder().collect();
+    ///
+    /// assert_eq!(values, vec![&1, &2, &3]);
+    /// ```
+    pub fn iter_inorder(&self) -> InOrderIter<'_, T> {
+        InOrderIter {
+            stack: Vec::new(),
+            current: Some(self),
+        }
+    }
+
+    /// Returns a mutable in-order iterator, yielding `&mut T`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use bintree::BinTree;
+    ///
+    /// let mut tree = BinTree::new_with_nodes(2, 1, 3);
+    /// for value in tree.iter_inorder_mut() {
+    ///     *value *= 10;
+    /// }
+    ///
+    /// let values: Vec<_> = tree.iter_inorder().collect();
+    /// assert_eq!(values, vec![&10, &20, &30]);
+    /// ```
+    pub fn iter_inorder_mut(&mut self) -> InOrderIterMut<'_, T> {
+        InOrderIterMut {
+            stack: Vec::new(),
+            current: Some(NonNull::from(self)),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Consumes the tree and returns an owned in-order iterator, yielding `T`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use bintree::BinTree;
+    ///
+    /// let tree = BinTree::new_with_nodes(2, 1, 3);
+    /// let values: Vec<_> = tree.into_inorder().collect();
+    ///
+    /// assert_eq!(values, vec![1, 2, 3]);
+    /// ```
+    pub fn into_inorder(self) -> IntoInOrderIter<T> {
+        IntoInOrderIter {
+            stack: Vec::new(),
+            current: Some(self.boxed()),
+        }
+    }
+
+    /// Returns an iterator that walks the tree post-order (left, right, node),
+    /// yielding `&T`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use bintree::BinTree;
+    ///
+    /// let tree = BinTree::new_with_nodes(1, 2, 3);
+    /// let values: Vec<_> = tree.iter_postorder().collect();
+    ///
+    /// assert_eq!(values, vec![&2, &3, &1]);
+    /// ```
+    pub fn iter_postorder(&self) -> PostOrderIter<'_, T> {
+        PostOrderIter::new(self)
+    }
+
+    /// Returns a mutable post-order iterator, yielding `&mut T`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use bintree::BinTree;
+    ///
+    /// let mut tree = BinTree::new_with_nodes(1, 2, 3);
+    /// for value in tree.iter_postorder_mut() {
+    ///     *value *= 10;
+    /// }
+    ///
+    /// let values: Vec<_> = tree.iter_postorder().collect();
+    /// assert_eq!(values, vec![&20, &30, &10]);
+    /// ```
+    pub fn iter_postorder_mut(&mut self) -> PostOrderIterMut<'_, T> {
+        PostOrderIterMut::new(self)
+    }
+
+    /// Consumes the tree and returns an owned post-order iterator, yielding `T`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use bintree::BinTree;
+    ///
+    /// let tree = BinTree::new_with_nodes(1, 2, 3);
+    /// let values: Vec<_> = tree.into_postorder().collect();
+    ///
+    /// assert_eq!(values, vec![2, 3, 1]);
+    /// ```
+    pub fn into_postorder(self) -> IntoPostOrderIter<T> {
+        IntoPostOrderIter::new(self)
+    }
+
+    /// Returns an iterator that walks the tree breadth-first, level by level,
+    /// yielding `&T`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use bintree::BinTree;
+    ///
+    /// let tree = BinTree::new_with_nodes(1, 2, 3);
+    /// let values: Vec<_> = tree.iter_bfs().collect();
+    ///
+    /// assert_eq!(values, vec![&1, &2, &3]);
+    /// ```
+    pub fn iter_bfs(&self) -> BfsIter<'_, T> {
+        BfsIter {
+            queue: VecDeque::from([self]),
+        }
+    }
+
+    /// Returns a mutable breadth-first iterator, yielding `&mut T`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use bintree::BinTree;
+    ///
+    /// let mut tree = BinTree::new_with_nodes(1, 2, 3);
+    /// for value in tree.iter_bfs_mut() {
+    ///     *value *= 10;
+    /// }
+    ///
+    /// let values: Vec<_> = tree.iter_bfs().collect();
+    /// assert_eq!(values, vec![&10, &20, &30]);
+    /// ```
+    pub fn iter_bfs_mut(&mut self) -> BfsIterMut<'_, T> {
+        BfsIterMut {
+            queue: VecDeque::from([NonNull::from(self)]),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Consumes the tree and returns an owned breadth-first iterator, yielding `T`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use bintree::BinTree;
+    ///
+    /// let tree = BinTree::new_with_nodes(1, 2, 3);
+    /// let values: Vec<_> = tree.into_bfs().collect();
+    ///
+    /// assert_eq!(values, vec![1, 2, 3]);
+    /// ```
+    pub fn into_bfs(self) -> IntoBfsIter<T> {
+        IntoBfsIter {
+            queue: VecDeque::from([self.boxed()]),
+        }
+    }
+}
+
+/// Pre-order (node, left, right) iterator over `&T`. See [`BinTree::iter_preorder`].
+pub struct PreOrderIter<'a, T> {
+    stack: Vec<&'a BinTree<T>>,
+}
+
+impl<'a, T> Iterator for PreOrderIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+
+        if let Some(right) = node.get_right() {
+            self.stack.push(right);
+        }
+        if let Some(left) = node.get_left() {
+            self.stack.push(left);
+        }
+
+        Some(node.get_inner())
+    }
+}
+
+/// Pre-order iterator over `&mut T`. See [`BinTree::iter_preorder_mut`].
+pub struct PreOrderIterMut<'a, T> {
+    stack: Vec<NonNull<BinTree<T>>>,
+    _marker: PhantomData<&'a mut BinTree<T>>,
+}
+
+impl<'a, T> Iterator for PreOrderIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut ptr = self.stack.pop()?;
+        // SAFETY: every pointer on the stack points at a distinct node reachable
+        // from the `&mut BinTree<T>` this iterator was built from. Subtrees never
+        // overlap, so each pointer is popped and dereferenced at most once and no
+        // two `&mut` borrows derived here ever alias.
+        let node: &'a mut BinTree<T> = unsafe { ptr.as_mut() };
+
+        if let Some(right) = node.get_right_mut() {
+            self.stack.push(NonNull::from(right));
+        }
+        if let Some(left) = node.get_left_mut() {
+            self.stack.push(NonNull::from(left));
+        }
+
+        Some(node.get_inner_mut())
+    }
+}
+
+/// Owned pre-order iterator over `T`. See [`BinTree::into_preorder`].
+pub struct IntoPreOrderIter<T> {
+    stack: Vec<Box<BinTree<T>>>,
+}
+
+impl<T> Iterator for IntoPreOrderIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut node = self.stack.pop()?;
+
+        if let Some(right) = node.pop_right() {
+            self.stack.push(right);
+        }
+        if let Some(left) = node.pop_left() {
+            self.stack.push(left);
+        }
+
+        Some(node.take_inner())
+    }
+}
+
+/// In-order (left, node, right) iterator over `&T`. See [`BinTree::iter_inorder`].
+pub struct InOrderIter<'a, T> {
+    stack: Vec<&'a BinTree<T>>,
+    current: Option<&'a BinTree<T>>,
+}
+
+impl<'a, T> Iterator for InOrderIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(node) = self.current.take() {
+            self.current = node.get_left();
+            self.stack.push(node);
+        }
+
+        let node = self.stack.pop()?;
+        self.current = node.get_right();
+        Some(node.get_inner())
+    }
+}
+
+/// In-order iterator over `&mut T`. See [`BinTree::iter_inorder_mut`].
+pub struct InOrderIterMut<'a, T> {
+    stack: Vec<NonNull<BinTree<T>>>,
+    current: Option<NonNull<BinTree<T>>>,
+    _marker: PhantomData<&'a mut BinTree<T>>,
+}
+
+impl<'a, T> Iterator for InOrderIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(mut ptr) = self.current.take() {
+            // SAFETY: see `PreOrderIterMut::next`; the same disjointness argument
+            // applies since nodes are only ever reachable through one path.
+            let node: &'a mut BinTree<T> = unsafe { ptr.as_mut() };
+            self.current = node.get_left_mut().map(NonNull::from);
+            self.stack.push(ptr);
+        }
+
+        let mut ptr = self.stack.pop()?;
+        let node: &'a mut BinTree<T> = unsafe { ptr.as_mut() };
+        self.current = node.get_right_mut().map(NonNull::from);
+        Some(node.get_inner_mut())
+    }
+}
+
+/// Owned in-order iterator over `T`. See [`BinTree::into_inorder`].
+pub struct IntoInOrderIter<T> {
+    stack: Vec<Box<BinTree<T>>>,
+    current: Option<Box<BinTree<T>>>,
+}
+
+impl<T> Iterator for IntoInOrderIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(mut node) = self.current.take() {
+            self.current = node.pop_left();
+            self.stack.push(node);
+        }
+
+        let mut node = self.stack.pop()?;
+        self.current = node.pop_right();
+        Some(node.take_inner())
+    }
+}
+
+/// Post-order (left, right, node) iterator over `&T`. See [`BinTree::iter_postorder`].
+///
+/// Built eagerly using the two-stack technique: a first stack walks the tree
+/// node-right-left, pushing every visited node onto a second stack, which then
+/// yields nodes in left-right-node order when popped.
+pub struct PostOrderIter<'a, T> {
+    output: Vec<&'a BinTree<T>>,
+}
+
+impl<'a, T> PostOrderIter<'a, T> {
+    fn new(root: &'a BinTree<T>) -> Self {
+        let mut pending = vec![root];
+        let mut output = Vec::new();
+
+        while let Some(node) = pending.pop() {
+            if let Some(left) = node.get_left() {
+                pending.push(left);
+            }
+            if let Some(right) = node.get_right() {
+                pending.push(right);
+            }
+            output.push(node);
+        }
+
+        Self { output }
+    }
+}
+
+impl<'a, T> Iterator for PostOrderIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.output.pop().map(BinTree::get_inner)
+    }
+}
+
+/// Post-order iterator over `&mut T`. See [`BinTree::iter_postorder_mut`].
+pub struct PostOrderIterMut<'a, T> {
+    output: Vec<NonNull<BinTree<T>>>,
+    _marker: PhantomData<&'a mut BinTree<T>>,
+}
+
+impl<'a, T> PostOrderIterMut<'a, T> {
+    fn new(root: &'a mut BinTree<T>) -> Self {
+        let mut pending = vec![NonNull::from(root)];
+        let mut output = Vec::new();
+
+        while let Some(mut ptr) = pending.pop() {
+            // SAFETY: see `PreOrderIterMut::next`.
+            let node: &'a mut BinTree<T> = unsafe { ptr.as_mut() };
+            if let Some(left) = node.get_left_mut() {
+                pending.push(NonNull::from(left));
+            }
+            if let Some(right) = node.get_right_mut() {
+                pending.push(NonNull::from(right));
+            }
+            output.push(ptr);
+        }
+
+        Self {
+            output,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Iterator for PostOrderIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut ptr = self.output.pop()?;
+        let node: &'a mut BinTree<T> = unsafe { ptr.as_mut() };
+        Some(node.get_inner_mut())
+    }
+}
+
+/// Owned post-order iterator over `T`. See [`BinTree::into_postorder`].
+pub struct IntoPostOrderIter<T> {
+    output: Vec<T>,
+}
+
+impl<T> IntoPostOrderIter<T> {
+    fn new(root: BinTree<T>) -> Self {
+        let mut pending = vec![root.boxed()];
+        let mut visited = Vec::new();
+
+        while let Some(mut node) = pending.pop() {
+            if let Some(left) = node.pop_left() {
+                pending.push(left);
+            }
+            if let Some(right) = node.pop_right() {
+                pending.push(right);
+            }
+            visited.push(node);
+        }
+
+        let output = visited.into_iter().map(|node| node.take_inner()).collect();
+        Self { output }
+    }
+}
+
+impl<T> Iterator for IntoPostOrderIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.output.pop()
+    }
+}
+
+/// Breadth-first (level by level) iterator over `&T`. See [`BinTree::iter_bfs`].
+pub struct BfsIter<'a, T> {
+    queue: VecDeque<&'a BinTree<T>>,
+}
+
+impl<'a, T> Iterator for BfsIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.queue.pop_front()?;
+
+        if let Some(left) = node.get_left() {
+            self.queue.push_back(left);
+        }
+        if let Some(right) = node.get_right() {
+            self.queue.push_back(right);
+        }
+
+        Some(node.get_inner())
+    }
+}
+
+/// Breadth-first iterator over `&mut T`. See [`BinTree::iter_bfs_mut`].
+pub struct BfsIterMut<'a, T> {
+    queue: VecDeque<NonNull<BinTree<T>>>,
+    _marker: PhantomData<&'a mut BinTree<T>>,
+}
+
+impl<'a, T> Iterator for BfsIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut ptr = self.queue.pop_front()?;
+        // SAFETY: see `PreOrderIterMut::next`.
+        let node: &'a mut BinTree<T> = unsafe { ptr.as_mut() };
+
+        if let Some(left) = node.get_left_mut() {
+            self.queue.push_back(NonNull::from(left));
+        }
+        if let Some(right) = node.get_right_mut() {
+            self.queue.push_back(NonNull::from(right));
+        }
+
+        Some(node.get_inner_mut())
+    }
+}
+
+/// Owned breadth-first iterator over `T`. See [`BinTree::into_bfs`].
+pub struct IntoBfsIter<T> {
+    queue: VecDeque<Box<BinTree<T>>>,
+}
+
+impl<T> Iterator for IntoBfsIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut node = self.queue.pop_front()?;
+
+        if let Some(left) = node.pop_left() {
+            self.queue.push_back(left);
+        }
+        if let Some(right) = node.pop_right() {
+            self.queue.push_back(right);
+        }
+
+        Some(node.take_inner())
+    }
+}
@@ -1,4 +1,7 @@
-use crate::BinTree;
+use crate::{
+    iter::{InOrderIter, InOrderIterMut, IntoInOrderIter},
+    BinTree,
+};
 use std::{
     fmt::{Debug, Display},
     ops::{Deref, DerefMut},
@@ -39,3 +42,67 @@ impl<T> DerefMut for BinTree<T> {
         &mut self.inner
     }
 }
+
+/// `for value in tree` walks the tree in-order, yielding owned `T`s.
+///
+/// ## Example
+/// ```rust
+/// use bintree::BinTree;
+///
+/// let tree = BinTree::new_with_nodes(2, 1, 3);
+/// let values: Vec<_> = tree.into_iter().collect();
+///
+/// assert_eq!(values, vec![1, 2, 3]);
+/// ```
+impl<T> IntoIterator for BinTree<T> {
+    type Item = T;
+    type IntoIter = IntoInOrderIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_inorder()
+    }
+}
+
+/// `for value in &tree` walks the tree in-order, yielding `&T`.
+///
+/// ## Example
+/// ```rust
+/// use bintree::BinTree;
+///
+/// let tree = BinTree::new_with_nodes(2, 1, 3);
+/// let values: Vec<_> = (&tree).into_iter().collect();
+///
+/// assert_eq!(values, vec![&1, &2, &3]);
+/// ```
+impl<'a, T> IntoIterator for &'a BinTree<T> {
+    type Item = &'a T;
+    type IntoIter = InOrderIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_inorder()
+    }
+}
+
+/// `for value in &mut tree` walks the tree in-order, yielding `&mut T`.
+///
+/// ## Example
+/// ```rust
+/// use bintree::BinTree;
+///
+/// let mut tree = BinTree::new_with_nodes(2, 1, 3);
+///
+/// for value in &mut tree {
+///     *value *= 10;
+/// }
+///
+/// let values: Vec<_> = (&tree).into_iter().collect();
+/// assert_eq!(values, vec![&10, &20, &30]);
+/// ```
+impl<'a, T> IntoIterator for &'a mut BinTree<T> {
+    type Item = &'a mut T;
+    type IntoIter = InOrderIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_inorder_mut()
+    }
+}
@@ -1,7 +1,11 @@
 ///! A binary tree library.
 use std::ops::Deref;
 
+mod bst;
+mod fallible;
 mod lang_extensions;
+pub mod iter;
+mod metrics;
 #[cfg(tests)]
 mod tests;
 
@@ -0,0 +1,113 @@
+//! Structural metrics describing the shape of a tree.
+
+use crate::BinTree;
+
+impl<T> BinTree<T> {
+    /// Returns the height of the tree: `1 + max(height(left), height(right))`,
+    /// with a missing child contributing `0`. A lone root therefore has a
+    /// height of `1`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use bintree::BinTree;
+    ///
+    /// let leaf: BinTree<i32> = BinTree::new(1);
+    /// assert_eq!(leaf.height(), 1);
+    ///
+    /// let tree = BinTree::new_with_nodes(1, 2, 3);
+    /// assert_eq!(tree.height(), 2);
+    /// ```
+    pub fn height(&self) -> usize {
+        let left = self.get_left().map_or(0, BinTree::height);
+        let right = self.get_right().map_or(0, BinTree::height);
+        1 + left.max(right)
+    }
+
+    /// Returns the depth (distance from the root, in edges) of the first node
+    /// found holding `value`, or `None` if it isn't in the tree.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use bintree::BinTree;
+    ///
+    /// let tree = BinTree::new_with_nodes(1, 2, 3);
+    /// assert_eq!(tree.depth_of(&1), Some(0));
+    /// assert_eq!(tree.depth_of(&3), Some(1));
+    /// assert_eq!(tree.depth_of(&99), None);
+    /// ```
+    pub fn depth_of(&self, value: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        fn search<T: PartialEq>(node: &BinTree<T>, value: &T, depth: usize) -> Option<usize> {
+            if node.get_inner() == value {
+                return Some(depth);
+            }
+
+            node.get_left()
+                .and_then(|left| search(left, value, depth + 1))
+                .or_else(|| node.get_right().and_then(|right| search(right, value, depth + 1)))
+        }
+
+        search(self, value, 0)
+    }
+
+    /// Returns the number of leaf nodes (nodes with no children) in the tree.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use bintree::BinTree;
+    ///
+    /// let tree = BinTree::new_with_nodes(1, 2, 3);
+    /// assert_eq!(tree.count_leaves(), 2);
+    /// ```
+    pub fn count_leaves(&self) -> usize {
+        match (self.get_left(), self.get_right()) {
+            (None, None) => 1,
+            (left, right) => {
+                left.map_or(0, BinTree::count_leaves) + right.map_or(0, BinTree::count_leaves)
+            }
+        }
+    }
+
+    /// Returns `true` if the tree is height-balanced: for every node, the
+    /// heights of its left and right subtrees differ by at most one.
+    ///
+    /// Runs a single bottom-up pass that returns each subtree's height, or
+    /// bails out as soon as an imbalance is found, so the whole check is
+    /// `O(n)` rather than recomputing [`height`](Self::height) at every node.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use bintree::BinTree;
+    ///
+    /// let mut tree = BinTree::new_with_nodes(1, 2, 3);
+    /// assert!(tree.is_balanced());
+    ///
+    /// let left = tree.get_left_mut().unwrap();
+    /// left.set_left(Some(4));
+    /// left.get_left_mut().unwrap().set_left(Some(5));
+    ///
+    /// assert!(!tree.is_balanced());
+    /// ```
+    pub fn is_balanced(&self) -> bool {
+        fn checked_height<T>(node: &BinTree<T>) -> Option<usize> {
+            let left = match node.get_left() {
+                Some(left) => checked_height(left)?,
+                None => 0,
+            };
+            let right = match node.get_right() {
+                Some(right) => checked_height(right)?,
+                None => 0,
+            };
+
+            if left.abs_diff(right) > 1 {
+                None
+            } else {
+                Some(1 + left.max(right))
+            }
+        }
+
+        checked_height(self).is_some()
+    }
+}